@@ -0,0 +1,156 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{aarch64::*, safe_arch, CheckLengthsSimd, CheckPow2, CheckPow2Size, CheckSameSize};
+use bounded_utils::{BoundedSlice, BoundedU32, BoundedU8, BoundedUsize};
+use zerocopy::{AsBytes, FromBytes};
+
+const NEON_VECTOR_SIZE: usize = 16;
+
+#[inline]
+#[target_feature(enable = "neon")]
+#[safe_arch]
+pub fn vld1q_load<T: AsBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+) -> uint8x16_t {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, NEON_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, NEON_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe { vld1q_u8(data.get_slice().as_ptr().add(start.get()) as *const u8) }
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+#[safe_arch]
+pub fn vst1q_store<T: FromBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &mut BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: uint8x16_t,
+) {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, NEON_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, NEON_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe {
+        vst1q_u8(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u8,
+            value,
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+#[safe_arch]
+pub fn vst1q_store_masked_u8<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU8<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: uint8x16_t,
+) {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, NEON_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`; the `BoundedU8` invariant is upheld by the
+    // `vandq_u8` operation.
+    unsafe {
+        vst1q_u8(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u8,
+            vandq_u8(vdupq_n_u8(VALUE_BOUND as u8 - 1), value),
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+#[safe_arch]
+pub fn vst1q_store_masked_u32<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU32<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: uint8x16_t,
+) {
+    let _ = CheckLengthsSimd::<u32, SLICE_BOUND, START_BOUND, NEON_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`; the `BoundedU32` invariant is upheld by the
+    // `vandq_u32` operation.
+    unsafe {
+        let masked = vandq_u32(
+            vdupq_n_u32(VALUE_BOUND as u32 - 1),
+            vreinterpretq_u32_u8(value),
+        );
+        vst1q_u8(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u8,
+            vreinterpretq_u8_u32(masked),
+        );
+    }
+}
+
+// NEON has no gather instruction, so this emulates `_mm256_masked_i32gather` by masking each
+// lane offset against `ARRAY_BOUND - 1` and performing four scalar indexed loads.
+#[inline]
+#[target_feature(enable = "neon")]
+#[safe_arch]
+pub fn vld1q_masked_gather<T: AsBytes, const SCALE: i32, const ARRAY_BOUND: usize>(
+    slice: &BoundedSlice<T, ARRAY_BOUND>,
+    offsets: uint32x4_t,
+) -> uint32x4_t {
+    let _ = CheckPow2::<ARRAY_BOUND>::IS_POW2;
+    let _ = CheckSameSize::<T, SCALE>::SAME_SIZE;
+    let mask = ARRAY_BOUND as u32 - 1;
+    let base = slice.get_slice().as_ptr().cast::<u8>();
+    // SAFETY: safety ensured by target_feature_11 + masking every extracted offset against
+    // `ARRAY_BOUND - 1`, which ensures each scalar load stays in-bounds.
+    unsafe {
+        let mut result = vdupq_n_u32(0);
+        result = vsetq_lane_u32::<0>(
+            core::ptr::read_unaligned(
+                base.add((vgetq_lane_u32::<0>(offsets) & mask) as usize * SCALE as usize)
+                    .cast(),
+            ),
+            result,
+        );
+        result = vsetq_lane_u32::<1>(
+            core::ptr::read_unaligned(
+                base.add((vgetq_lane_u32::<1>(offsets) & mask) as usize * SCALE as usize)
+                    .cast(),
+            ),
+            result,
+        );
+        result = vsetq_lane_u32::<2>(
+            core::ptr::read_unaligned(
+                base.add((vgetq_lane_u32::<2>(offsets) & mask) as usize * SCALE as usize)
+                    .cast(),
+            ),
+            result,
+        );
+        result = vsetq_lane_u32::<3>(
+            core::ptr::read_unaligned(
+                base.add((vgetq_lane_u32::<3>(offsets) & mask) as usize * SCALE as usize)
+                    .cast(),
+            ),
+            result,
+        );
+        result
+    }
+}
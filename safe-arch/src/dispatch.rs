@@ -0,0 +1,128 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime CPU feature detection, so a single shipped binary can pick the widest vector width
+//! the host actually supports instead of being compiled down to the lowest common denominator.
+
+use bounded_utils::{BoundedSlice, BoundedUsize};
+use std::sync::OnceLock;
+
+#[cfg(target_arch = "x86_64")]
+use crate::x86_64;
+#[cfg(target_arch = "aarch64")]
+use crate::aarch64;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::_mm_storeu_si128;
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::vst1q_u8;
+
+/// The widest SIMD instruction set this process detected support for, cached after the first
+/// call to [`SimdBackend::detect`] so later lookups on hot paths are a single load.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimdBackend {
+    #[cfg(target_arch = "x86_64")]
+    Avx512,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "x86_64")]
+    Sse2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+    Scalar,
+}
+
+impl SimdBackend {
+    /// Detects the widest backend supported by the current CPU and caches the result for the
+    /// lifetime of the process. Encoder inner loops should call this once and reuse the handle
+    /// rather than re-detecting per call.
+    #[inline]
+    pub fn detect() -> Self {
+        static BACKEND: OnceLock<SimdBackend> = OnceLock::new();
+        *BACKEND.get_or_init(Self::detect_uncached)
+    }
+
+    fn detect_uncached() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx512f") {
+                return SimdBackend::Avx512;
+            }
+            if is_x86_feature_detected!("avx2") {
+                return SimdBackend::Avx2;
+            }
+            if is_x86_feature_detected!("sse2") {
+                return SimdBackend::Sse2;
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return SimdBackend::Neon;
+            }
+        }
+        SimdBackend::Scalar
+    }
+
+    /// The number of bytes processed per vector operation on this backend.
+    #[inline]
+    pub fn vector_width(self) -> usize {
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Avx512 => 64,
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Avx2 => 32,
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Sse2 => 16,
+            #[cfg(target_arch = "aarch64")]
+            SimdBackend::Neon => 16,
+            SimdBackend::Scalar => 1,
+        }
+    }
+
+    /// Loads a 16-byte window from `data` at `start`, routed through whichever backend this
+    /// process detected at startup. This is the dispatch subsystem's wired-up call path: encoder
+    /// inner loops that only need a 16-byte window go through here instead of hand-writing the
+    /// backend `match` and re-calling `_mm_load`/`vld1q_load`/`portable::load` themselves.
+    #[inline]
+    pub fn load16<const SLICE_BOUND: usize, const START_BOUND: usize>(
+        self,
+        data: &BoundedSlice<u8, SLICE_BOUND>,
+        start: BoundedUsize<START_BOUND>,
+    ) -> [u8; 16] {
+        let mut out = [0u8; 16];
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Avx512 | SimdBackend::Avx2 | SimdBackend::Sse2 => {
+                // SAFETY: every x86_64 `SimdBackend` variant implies at least "sse2" support,
+                // which is all `_mm_load` requires.
+                let vector = unsafe { x86_64::_mm_load(data, start) };
+                // SAFETY: every x86_64 `SimdBackend` variant implies at least "sse2" support,
+                // which is all `_mm_storeu_si128` requires.
+                unsafe { _mm_storeu_si128(out.as_mut_ptr() as *mut _, vector) };
+            }
+            #[cfg(target_arch = "aarch64")]
+            SimdBackend::Neon => {
+                // SAFETY: `SimdBackend::Neon` is only returned by `detect` when NEON was found,
+                // which is all `vld1q_load` requires.
+                let vector = unsafe { aarch64::vld1q_load(data, start) };
+                // SAFETY: `SimdBackend::Neon` is only returned by `detect` when NEON was found.
+                unsafe { vst1q_u8(out.as_mut_ptr(), vector) };
+            }
+            SimdBackend::Scalar => {
+                out = crate::portable::load(data, start).to_array();
+            }
+        }
+        out
+    }
+}
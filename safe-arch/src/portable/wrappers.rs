@@ -0,0 +1,136 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A portable backend built on `core::simd`, compiled behind the `portable-simd` feature. It
+//! mirrors the bounded API of the arch-specific backends so callers can fall back to it on any
+//! target that doesn't have a specialized implementation.
+
+use crate::{safe_arch, CheckLengthsSimd, CheckPow2, CheckPow2Size, CheckSameSize};
+use bounded_utils::{BoundedSlice, BoundedU32, BoundedU8, BoundedUsize};
+use core::simd::{u32x4, u8x16, Simd};
+use zerocopy::{AsBytes, FromBytes};
+
+const PORTABLE_VECTOR_SIZE: usize = 16;
+
+#[inline]
+#[safe_arch]
+pub fn load<T: AsBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+) -> u8x16 {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, PORTABLE_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, PORTABLE_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: the above length check ensures that a full vector can still be read after `start`.
+    let bytes = unsafe {
+        core::slice::from_raw_parts(
+            data.get_slice().as_ptr().add(start.get()) as *const u8,
+            PORTABLE_VECTOR_SIZE,
+        )
+    };
+    Simd::from_slice(bytes)
+}
+
+#[inline]
+#[safe_arch]
+pub fn store<T: FromBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &mut BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: u8x16,
+) {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, PORTABLE_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, PORTABLE_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: the above length check ensures that a full vector can still be written after
+    // `start`.
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u8,
+            PORTABLE_VECTOR_SIZE,
+        )
+    };
+    value.copy_to_slice(bytes);
+}
+
+#[inline]
+#[safe_arch]
+pub fn store_masked_u8<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU8<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: u8x16,
+) {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, PORTABLE_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // The `BoundedU8` invariant is upheld by masking every lane with `VALUE_BOUND - 1`.
+    let masked = value & Simd::splat(VALUE_BOUND as u8 - 1);
+    // SAFETY: the above length check ensures that a full vector can still be written after
+    // `start`.
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u8,
+            PORTABLE_VECTOR_SIZE,
+        )
+    };
+    masked.copy_to_slice(bytes);
+}
+
+#[inline]
+#[safe_arch]
+pub fn store_masked_u32<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU32<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: u32x4,
+) {
+    let _ = CheckLengthsSimd::<u32, SLICE_BOUND, START_BOUND, PORTABLE_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // The `BoundedU32` invariant is upheld by masking every lane with `VALUE_BOUND - 1`.
+    let masked = value & Simd::splat(VALUE_BOUND as u32 - 1);
+    // SAFETY: the above length check ensures that a full vector can still be written after
+    // `start`.
+    let words = unsafe {
+        core::slice::from_raw_parts_mut(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut u32,
+            4,
+        )
+    };
+    masked.copy_to_slice(words);
+}
+
+#[inline]
+#[safe_arch]
+pub fn masked_i32gather<T: AsBytes, const SCALE: i32, const ARRAY_BOUND: usize>(
+    slice: &BoundedSlice<T, ARRAY_BOUND>,
+    offsets: u32x4,
+) -> u32x4 {
+    let _ = CheckPow2::<ARRAY_BOUND>::IS_POW2;
+    let _ = CheckSameSize::<T, SCALE>::SAME_SIZE;
+    let mask = ARRAY_BOUND as u32 - 1;
+    let base = slice.get_slice().as_ptr().cast::<u8>();
+    // SAFETY: masking every offset against `ARRAY_BOUND - 1` and scaling by `size_of::<T>()`
+    // (rather than assuming a 4-byte element, as `T` may be narrower) ensures every gathered
+    // index stays in-bounds of `slice`.
+    let lanes = unsafe {
+        core::array::from_fn::<u32, 4, _>(|lane| {
+            let offset = (offsets[lane] & mask) as usize * SCALE as usize;
+            core::ptr::read_unaligned(base.add(offset).cast())
+        })
+    };
+    Simd::from_array(lanes)
+}
@@ -0,0 +1,140 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{safe_arch, wasm32::*, CheckLengthsSimd, CheckPow2, CheckPow2Size, CheckSameSize};
+use bounded_utils::{BoundedSlice, BoundedU32, BoundedU8, BoundedUsize};
+use zerocopy::{AsBytes, FromBytes};
+
+const WASM_VECTOR_SIZE: usize = 16;
+
+#[inline]
+#[target_feature(enable = "simd128")]
+#[safe_arch]
+pub fn v128_load_bounded<T: AsBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+) -> v128 {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, WASM_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, WASM_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe { v128_load(data.get_slice().as_ptr().add(start.get()) as *const v128) }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+#[safe_arch]
+pub fn v128_store_bounded<T: FromBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &mut BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: v128,
+) {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, WASM_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, WASM_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be written after `start`.
+    unsafe {
+        v128_store(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut v128,
+            value,
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+#[safe_arch]
+pub fn v128_store_masked_u8<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU8<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: v128,
+) {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, WASM_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be written after `start`; the `BoundedU8` invariant is upheld by the
+    // `v128_and` operation.
+    unsafe {
+        v128_store(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut v128,
+            v128_and(i8x16_splat(VALUE_BOUND as i8 - 1), value),
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+#[safe_arch]
+pub fn v128_store_masked_u32<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU32<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: v128,
+) {
+    let _ = CheckLengthsSimd::<u32, SLICE_BOUND, START_BOUND, WASM_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be written after `start`; the `BoundedU32` invariant is upheld by
+    // the `v128_and` operation.
+    unsafe {
+        v128_store(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut v128,
+            v128_and(i32x4_splat(VALUE_BOUND as i32 - 1), value),
+        );
+    }
+}
+
+// wasm has no gather instruction, so this emulates `_mm256_masked_i32gather` by extracting each
+// lane, masking it against `ARRAY_BOUND - 1`, and performing four scalar indexed loads.
+#[inline]
+#[target_feature(enable = "simd128")]
+#[safe_arch]
+pub fn v128_masked_i32gather<T: AsBytes, const SCALE: i32, const ARRAY_BOUND: usize>(
+    slice: &BoundedSlice<T, ARRAY_BOUND>,
+    offsets: v128,
+) -> v128 {
+    let _ = CheckPow2::<ARRAY_BOUND>::IS_POW2;
+    let _ = CheckSameSize::<T, SCALE>::SAME_SIZE;
+    let mask = ARRAY_BOUND as u32 - 1;
+    let base = slice.get_slice().as_ptr().cast::<u8>();
+    // SAFETY: safety ensured by target_feature_11 + masking every extracted offset against
+    // `ARRAY_BOUND - 1`, which ensures each scalar load stays in-bounds.
+    unsafe {
+        let mut result = [0u32; 4];
+        for (lane, entry) in result.iter_mut().enumerate() {
+            let offset = match lane {
+                0 => i32x4_extract_lane::<0>(offsets) as u32,
+                1 => i32x4_extract_lane::<1>(offsets) as u32,
+                2 => i32x4_extract_lane::<2>(offsets) as u32,
+                _ => i32x4_extract_lane::<3>(offsets) as u32,
+            };
+            *entry = core::ptr::read_unaligned(
+                base.add((offset & mask) as usize * SCALE as usize).cast(),
+            );
+        }
+        i32x4(
+            result[0] as i32,
+            result[1] as i32,
+            result[2] as i32,
+            result[3] as i32,
+        )
+    }
+}
@@ -206,3 +206,223 @@ pub fn _mm_store_masked_u32<
         );
     }
 }
+
+const AVX_512_VECTOR_SIZE: usize = 64;
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[safe_arch]
+pub fn _mm512_load<T: AsBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+) -> __m512i {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, AVX_512_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, AVX_512_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe { _mm512_loadu_si512(data.get_slice().as_ptr().add(start.get()) as *const _) }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[safe_arch]
+pub fn _mm512_store<T: FromBytes, const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &mut BoundedSlice<T, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: __m512i,
+) {
+    let _ = CheckLengthsSimd::<T, SLICE_BOUND, START_BOUND, AVX_512_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2Size::<T, AVX_512_VECTOR_SIZE>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe {
+        _mm512_storeu_si512(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut _,
+            value,
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[safe_arch]
+pub fn _mm512_store_masked_u8<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU8<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: __m512i,
+) {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, AVX_512_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`; the `BoundedU8` invariant is upheld by the
+    // `_mm512_and_si512` operation.
+    unsafe {
+        _mm512_storeu_si512(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut _,
+            _mm512_and_si512(_mm512_set1_epi8(VALUE_BOUND as i8 - 1), value),
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[safe_arch]
+pub fn _mm512_store_masked_u32<
+    const SLICE_BOUND: usize,
+    const START_BOUND: usize,
+    const VALUE_BOUND: usize,
+>(
+    data: &mut BoundedSlice<BoundedU32<VALUE_BOUND>, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    value: __m512i,
+) {
+    let _ = CheckLengthsSimd::<u32, SLICE_BOUND, START_BOUND, AVX_512_VECTOR_SIZE>::CHECK_GE;
+    let _ = CheckPow2::<VALUE_BOUND>::IS_POW2;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`; the `BoundedU32` invariant is upheld by the
+    // `_mm512_and_si512` operation.
+    unsafe {
+        _mm512_storeu_si512(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut _,
+            _mm512_and_si512(_mm512_set1_epi32(VALUE_BOUND as i32 - 1), value),
+        );
+    }
+}
+
+// Unlike the 256/128-bit stores, partial vectors at the tail of a slice don't need a separate
+// scalar epilogue: AVX-512's native `__mmask64` predication lets `_mm512_maskz_loadu_epi8`/
+// `_mm512_mask_storeu_epi8` skip the out-of-bounds lanes directly.
+#[inline]
+#[target_feature(enable = "avx512f,avx512bw")]
+#[safe_arch]
+pub fn _mm512_load_tail<const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<u8, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    valid_bytes: BoundedUsize<AVX_512_VECTOR_SIZE>,
+) -> __m512i {
+    // `data` is bounded in bytes (`T = u8`), matching the byte count `valid_bytes` and the mask
+    // both already operate in, so this assertion is directly comparable with no unit mismatch.
+    assert!(start.get() + valid_bytes.get() <= SLICE_BOUND);
+    let mask: __mmask64 = if valid_bytes.get() >= AVX_512_VECTOR_SIZE {
+        u64::MAX
+    } else {
+        (1u64 << valid_bytes.get()) - 1
+    };
+    // SAFETY: safety ensured by target_feature_11; the above assertion ties `start` and
+    // `valid_bytes` to `SLICE_BOUND`, and the mask ensures only the first `valid_bytes` lanes
+    // starting at `start` are read, so reads never cross `data`'s end.
+    unsafe {
+        _mm512_maskz_loadu_epi8(
+            mask,
+            data.get_slice().as_ptr().add(start.get()) as *const _,
+        )
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f,avx512bw")]
+#[safe_arch]
+pub fn _mm512_store_tail<const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &mut BoundedSlice<u8, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    valid_bytes: BoundedUsize<AVX_512_VECTOR_SIZE>,
+    value: __m512i,
+) {
+    // `data` is bounded in bytes (`T = u8`), matching the byte count `valid_bytes` and the mask
+    // both already operate in, so this assertion is directly comparable with no unit mismatch.
+    assert!(start.get() + valid_bytes.get() <= SLICE_BOUND);
+    let mask: __mmask64 = if valid_bytes.get() >= AVX_512_VECTOR_SIZE {
+        u64::MAX
+    } else {
+        (1u64 << valid_bytes.get()) - 1
+    };
+    // SAFETY: safety ensured by target_feature_11; the above assertion ties `start` and
+    // `valid_bytes` to `SLICE_BOUND`, and the mask ensures only the first `valid_bytes` lanes
+    // starting at `start` are written, so writes never cross `data`'s end.
+    unsafe {
+        _mm512_mask_storeu_epi8(
+            data.get_slice_mut().as_mut_ptr().add(start.get()) as *mut _,
+            mask,
+            value,
+        );
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[safe_arch]
+pub fn _mm512_masked_i32gather<T: AsBytes, const SCALE: i32, const ARRAY_BOUND: usize>(
+    slice: &BoundedSlice<T, ARRAY_BOUND>,
+    offsets: __m512i,
+) -> __m512i {
+    let _ = CheckPow2::<ARRAY_BOUND>::IS_POW2;
+    let _ = CheckSameSize::<T, SCALE>::SAME_SIZE;
+    // SAFETY: safety ensured by target_feature_11 + the _mm512_and_si512 operation that
+    // ensures no OOB read can happen.
+    unsafe {
+        _mm512_mask_i32gather_epi32::<SCALE>(
+            _mm512_setzero_si512(),
+            u16::MAX,
+            _mm512_and_si512(offsets, _mm512_set1_epi32(ARRAY_BOUND as i32 - 1)),
+            slice.get_slice().as_ptr().cast(),
+        )
+    }
+}
+
+// Constant XORed into the SSE2 fallback for `aes_hash` below, after that fallback multiplies
+// each 32-bit lane of the window by `key`. Both functions return a `__m128i`, so the match
+// finder gets the same output width whether or not AES-NI is available.
+const HASH_MULTIPLIER: u64 = 0x9E3779B97F4A7C15;
+
+// SSE2 has no 32x32->32 lane multiply (`_mm_mullo_epi32` is SSE4.1), so this emulates it with
+// the classic `_mm_mul_epu32`-based sequence: multiply the even and odd lanes separately via
+// the widening unsigned multiply, then shuffle the low 32 bits of each result back together.
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn mullo_epi32_sse2(a: __m128i, b: __m128i) -> __m128i {
+    let even = _mm_mul_epu32(a, b);
+    let odd = _mm_mul_epu32(_mm_srli_si128(a, 4), _mm_srli_si128(b, 4));
+    _mm_unpacklo_epi32(
+        _mm_shuffle_epi32(even, 0b00_00_10_00),
+        _mm_shuffle_epi32(odd, 0b00_00_10_00),
+    )
+}
+
+#[inline]
+#[target_feature(enable = "aes")]
+#[safe_arch]
+pub fn aes_hash<const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<u8, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    key: __m128i,
+) -> __m128i {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, SSE_VECTOR_SIZE>::CHECK_GE;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe {
+        let window = _mm_loadu_si128(data.get_slice().as_ptr().add(start.get()) as *const _);
+        _mm_aesenc_si128(_mm_aesenc_si128(window, key), key)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+#[safe_arch]
+pub fn aes_hash_fallback<const SLICE_BOUND: usize, const START_BOUND: usize>(
+    data: &BoundedSlice<u8, SLICE_BOUND>,
+    start: BoundedUsize<START_BOUND>,
+    key: __m128i,
+) -> __m128i {
+    let _ = CheckLengthsSimd::<u8, SLICE_BOUND, START_BOUND, SSE_VECTOR_SIZE>::CHECK_GE;
+    // SAFETY: safety ensured by target_feature_11 + the above length check, which ensures that a
+    // full vector can still be read after `start`.
+    unsafe {
+        let window = _mm_loadu_si128(data.get_slice().as_ptr().add(start.get()) as *const _);
+        let mixed = mullo_epi32_sse2(window, key);
+        _mm_xor_si128(mixed, _mm_set1_epi64x(HASH_MULTIPLIER as i64))
+    }
+}